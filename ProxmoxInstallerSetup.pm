@@ -9,18 +9,24 @@ my $product_cfg = {
 	port => '8006',
 	enable_btrfs => 1,
 	bridged_network => 1,
+	min_hdsize => 8, # in GB
+	recommended_hdsize => 32, # in GB
     },
     pmg => {
 	fullname => 'Proxmox Mail Gateway',
 	port => '8006',
 	enable_btrfs => 0,
 	bridged_network => 0,
+	min_hdsize => 8, # in GB
+	recommended_hdsize => 16, # in GB
     },
     pbs => {
 	fullname => 'Proxmox Backup Server',
 	port => '8007',
 	enable_btrfs => 0,
 	bridged_network => 0,
+	min_hdsize => 8, # in GB
+	recommended_hdsize => 32, # in GB
     },
 };
 
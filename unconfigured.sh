@@ -21,6 +21,7 @@ debugsh() {
 }
 
 eject_and_reboot() {
+    action=${1:-reboot}
     iso_dev=$(awk '/ iso9660 / {print $1}' /proc/mounts)
 
     for try in 5 4 3 2 1; do
@@ -40,13 +41,19 @@ eject_and_reboot() {
 
     umount -l -n /dev
 
-    echo "rebooting - please remove the ISO boot media"
+    if [ "$action" = "poweroff" ]; then
+        cmd="poweroff" sysrq="o" msg="powering off" sysrq_msg="power off"
+    else
+        cmd="reboot" sysrq="b" msg="rebooting" sysrq_msg="reset"
+    fi
+
+    echo "$msg - please remove the ISO boot media"
     sleep 3
-    reboot -f
+    $cmd -f
     sleep 5
-    echo "trigger reset system request"
-    # we do not expect the reboot above to fail, so rather to avoid kpanic when pid 1 exits
-    echo b > /proc/sysrq-trigger
+    echo "trigger $sysrq_msg system request"
+    # we do not expect the reboot/poweroff above to fail, so rather to avoid kpanic when pid 1 exits
+    echo $sysrq > /proc/sysrq-trigger
     sleep 100
 }
 
@@ -86,7 +93,7 @@ real_reboot() {
     kill -s KILL -1 # KILL all but current init (our self) PID 1
     sleep 0.5
 
-    eject_and_reboot
+    eject_and_reboot "$1"
 
     exit 0 # shouldn't be reached, kernel will panic in that case
 }
@@ -211,10 +218,15 @@ if [ $proxdebug -ne 0 ]; then
     debugsh || true
 fi
 
-echo "Installation done, rebooting... "
-
 killall5 -15
 
+if [ -f /run/proxmox-installer-poweroff ]; then
+    echo "Installation done, powering off... "
+    real_reboot poweroff
+fi
+
+echo "Installation done, rebooting... "
+
 real_reboot
 
 # never reached